@@ -1,26 +1,37 @@
-use iced::widget::{button, checkbox, column, container, focus_next, row, text, text_input, Row};
-use iced::{Element, Length, Right, Subscription, Task, time};
+use iced::widget::{button, checkbox, column, container, focus_next, mouse_area, row, text, text_input, Row};
+use iced::{Element, Event, Length, Point, Right, Subscription, Task, time, mouse};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::vec;
 use uuid::Uuid;
-use taskmanager::project::{self, ProjectData, Project};
+use taskmanager::project::{self, ProjectData, Project, Task as ProjectTask, TaskStatus, Storage, FileStorage, SqliteStorage};
+
+type SharedStorage = Arc<Mutex<Box<dyn Storage + Send>>>;
 
 #[derive(Debug, Clone)]
 enum Context {
 	ProjectList,
 	NewProject,
 	EditProject,
+	ProjectDetail(Uuid),
+	Board(Uuid),
+	Focus,
 }
 
 #[derive(Debug, Clone)]
 struct ProjectListState {
 	selected_projects: Vec<Uuid>,
+	search_query: String,
+	show_archived: bool,
 }
 
 impl ProjectListState {
 	fn new() -> Self {
 		Self {
 			selected_projects: Vec::new(),
+			search_query: "".to_string(),
+			show_archived: false,
 		}
 	}
 }
@@ -55,6 +66,42 @@ impl ProjectFormState {
 	}
 }
 
+#[derive(Debug, Clone)]
+struct ProjectDetailState {
+	new_task_title: String,
+	new_task_description: String,
+}
+
+impl ProjectDetailState {
+	fn new() -> Self {
+		Self {
+			new_task_title: "".to_string(),
+			new_task_description: "".to_string(),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+struct DragState {
+	project_id: Uuid,
+	task_id: Uuid,
+	cursor: Point,
+	hovered_status: Option<TaskStatus>,
+}
+
+#[derive(Debug, Clone)]
+struct FocusState {
+	skipped: Vec<Uuid>,
+}
+
+impl FocusState {
+	fn new() -> Self {
+		Self {
+			skipped: Vec::new(),
+		}
+	}
+}
+
 #[derive(Debug, Clone)]
 struct AppState {
 	context: Option<Context>,
@@ -62,6 +109,10 @@ struct AppState {
 	current_project: Option<Project>,
 	project_list_state: Option<ProjectListState>,
 	project_form_state: Option<ProjectFormState>,
+	project_detail_state: Option<ProjectDetailState>,
+	drag_state: Option<DragState>,
+	focus_state: Option<FocusState>,
+	storage: SharedStorage,
 }
 
 #[derive(Debug)]
@@ -82,6 +133,29 @@ enum Message {
 	CurrentProjectDescriptionChange(String),
 	ProjectListProjectSelected { selected: bool, selected_project_id: Uuid },
 	ProjectListSelectAllProjects(bool),
+	OpenProject(Uuid),
+	CloseProjectDetail,
+	NewTaskTitleChange(String),
+	NewTaskDescriptionChange(String),
+	NewTask(Uuid),
+	TaskTitleChange(Uuid, Uuid, String),
+	TaskDescriptionChange(Uuid, Uuid, String),
+	TaskStatusChange(Uuid, Uuid, TaskStatus),
+	TaskPriorityChange(Uuid, Uuid, i32),
+	OpenBoard(Uuid),
+	CloseBoard(Uuid),
+	TaskDragStarted(Uuid),
+	TaskDragMoved(Point),
+	TaskDragHover(TaskStatus),
+	TaskDropped { status: TaskStatus },
+	ProjectSearchChange(String),
+	DeleteSelectedProjects,
+	ArchiveSelectedProjects,
+	ToggleShowArchived(bool),
+	EnterFocusMode,
+	FocusDone,
+	FocusSkip,
+	ExitFocusMode,
 }
 
 impl Default for AppState {
@@ -92,10 +166,41 @@ impl Default for AppState {
 			current_project: None,
 			project_list_state: None,
 			project_form_state: None,
+			project_detail_state: None,
+			drag_state: None,
+			focus_state: None,
+			storage: Arc::new(Mutex::new(build_storage())),
 		}
 	}
 }
 
+const FUZZY_MATCH_THRESHOLD: f64 = 0.15;
+
+fn trigrams(value: &str) -> HashSet<String> {
+	let normalized = value.to_lowercase();
+	let chars: Vec<char> = normalized.chars().collect();
+
+	if chars.len() < 3 {
+		return HashSet::from([normalized]);
+	}
+
+	chars.windows(3).map(|window| window.iter().collect()).collect()
+}
+
+fn fuzzy_score(query: &str, target: &str) -> f64 {
+	let query_trigrams = trigrams(query);
+	let target_trigrams = trigrams(target);
+
+	let intersection = query_trigrams.intersection(&target_trigrams).count();
+	let union = query_trigrams.union(&target_trigrams).count();
+
+	if union == 0 {
+		0.0
+	} else {
+		intersection as f64 / union as f64
+	}
+}
+
 fn heading(heading_text: &str) -> Element<Message> {
 	container(
 		text(heading_text)
@@ -135,10 +240,102 @@ fn project_form<'a>(project: &'a project::Project, form: &'a ProjectFormState) -
 	.into()
 }
 
+fn task_row<'a>(project_id: Uuid, project_task: &'a ProjectTask) -> Element<'a, Message> {
+	let task_id = project_task.id;
+
+	Row::new()
+		.push(
+			text_input("Task title", project_task.title.as_str())
+				.on_input(move |value| Message::TaskTitleChange(project_id, task_id, value))
+		)
+		.push(
+			text_input("Task description", project_task.description.as_str())
+				.on_input(move |value| Message::TaskDescriptionChange(project_id, task_id, value))
+		)
+		.push(text(format!("{:?}", project_task.status)))
+		.push(button("Todo").on_press(Message::TaskStatusChange(project_id, task_id, TaskStatus::Todo)))
+		.push(button("Start").on_press(Message::TaskStatusChange(project_id, task_id, TaskStatus::InProgress)))
+		.push(button("Done").on_press(Message::TaskStatusChange(project_id, task_id, TaskStatus::Done)))
+		.push(text(format!("Priority: {}", project_task.priority)))
+		.push(button("+").on_press(Message::TaskPriorityChange(project_id, task_id, 1)))
+		.push(button("-").on_press(Message::TaskPriorityChange(project_id, task_id, -1)))
+		.spacing(15)
+		.into()
+}
+
+fn task_card<'a>(project_task: &'a ProjectTask, is_dragged: bool) -> Element<'a, Message> {
+	let task_id = project_task.id;
+
+	mouse_area(
+		container(text(project_task.title.clone()))
+			.padding(10)
+			.width(Length::Fill)
+			.style(move |theme| {
+				if is_dragged {
+					container::rounded_box(theme).background(iced::Color::from_rgb(0.55, 0.75, 1.0))
+				} else {
+					container::transparent(theme)
+				}
+			})
+	)
+	.on_press(Message::TaskDragStarted(task_id))
+	.into()
+}
+
+fn board_column<'a>(title: &'a str, status: TaskStatus, tasks: &[&'a ProjectTask], dragged_task_id: Option<Uuid>, is_hovered: bool) -> Element<'a, Message> {
+	let cards: Vec<Element<Message>> = tasks.iter()
+		.map(|project_task| task_card(project_task, Some(project_task.id) == dragged_task_id))
+		.collect();
+
+	mouse_area(
+		container(
+			column![
+				text(title).size(20),
+				column(cards).spacing(10),
+			]
+			.spacing(10)
+		)
+		.padding(10)
+		.width(Length::FillPortion(1))
+		.style(move |theme| {
+			if is_hovered {
+				container::bordered_box(theme)
+			} else {
+				container::transparent(theme)
+			}
+		})
+	)
+	.on_enter(Message::TaskDragHover(status))
+	.on_release(Message::TaskDropped { status })
+	.into()
+}
+
+fn log_storage_error(operation: &str, result: Result<(), String>) {
+	if let Err(error) = result {
+		println!("Error occurred while {}: {}", operation, error);
+	}
+}
+
+fn build_storage() -> Box<dyn Storage + Send> {
+	match std::env::var("TASKMANAGER_BACKEND").as_deref() {
+		Ok("sqlite") => {
+			match SqliteStorage::new(std::path::Path::new("taskmanager.sqlite3")) {
+				Ok(storage) => Box::new(storage),
+				Err(error) => {
+					println!("Error occurred while opening the sqlite backend, falling back to the file backend: {}", error);
+					Box::new(FileStorage::new())
+				}
+			}
+		},
+		_ => Box::new(FileStorage::new()),
+	}
+}
+
 impl AppState {
 	async fn load() -> Result<AppState, String> {
 		println!("Loading data");
-		let load_result = project::load_data();
+		let mut storage = build_storage();
+		let load_result = storage.load();
 
 		match load_result {
 			Ok(projects_data) => {
@@ -149,12 +346,17 @@ impl AppState {
 					current_project: None,
 					project_list_state: Some(ProjectListState::new()),
 					project_form_state: Some(ProjectFormState::new()),
+					project_detail_state: None,
+					drag_state: None,
+					focus_state: None,
+					storage: Arc::new(Mutex::new(storage)),
 				})
 			},
 			Err(error) => {
 				println!("Error occurred while loading data: {}", error);
 
 				Ok(Self {
+					storage: Arc::new(Mutex::new(storage)),
 					..AppState::default()
 				})
 			}
@@ -163,7 +365,7 @@ impl AppState {
 
 	fn save(&self) -> Result<(), String> {
 		println!("Saving data");
-		let save_result = project::write_data(self.projects_data.as_ref().unwrap());
+		let save_result = self.storage.lock().unwrap().save(self.projects_data.as_ref().unwrap());
 
 		match save_result {
 			Ok(_) => {
@@ -192,6 +394,15 @@ impl AppState {
 					},
 					Context::EditProject => {
 						self.view_edit_project()
+					},
+					Context::ProjectDetail(project_id) => {
+						self.view_project_detail(project_id)
+					},
+					Context::Board(project_id) => {
+						self.view_board(project_id)
+					},
+					Context::Focus => {
+						self.view_focus()
 					}
 				}
 			}
@@ -205,6 +416,39 @@ impl AppState {
 		}
 	}
 
+	fn filtered_projects(&self) -> Vec<Project> {
+		let show_archived = match &self.project_list_state {
+			None => false,
+			Some(project_list_state) => project_list_state.show_archived,
+		};
+
+		let projects: Vec<Project> = self.get_projects()
+			.into_iter()
+			.filter(|project| show_archived || !project.archived)
+			.collect();
+
+		let query = match &self.project_list_state {
+			None => "".to_string(),
+			Some(project_list_state) => project_list_state.search_query.trim().to_string(),
+		};
+
+		if query.is_empty() {
+			return projects;
+		}
+
+		let mut scored_projects: Vec<(f64, Project)> = projects.into_iter()
+			.map(|project| {
+				let haystack = format!("{} {}", project.name, project.description);
+				(fuzzy_score(&query, &haystack), project)
+			})
+			.filter(|(score, _)| *score > FUZZY_MATCH_THRESHOLD)
+			.collect();
+
+		scored_projects.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+
+		scored_projects.into_iter().map(|(_, project)| project).collect()
+	}
+
 	fn save_current_project(&mut self) {
 		let current_project = &mut self.current_project;
 		if let Some(current_project) = current_project {
@@ -214,14 +458,12 @@ impl AppState {
 				if let Some(context) = context {
 					match context {
 						Context::NewProject => {
-							projects_data.create_project(&current_project.name, &current_project.description);
+							let result = self.storage.lock().unwrap().create_project(projects_data, &current_project.name, &current_project.description);
+							log_storage_error("creating project", result);
 						},
 						Context::EditProject => {
-							let project: Option<&mut Project> = projects_data.get_project_mut(&current_project.id);
-							if let Some(project) = project {
-								project.name = current_project.name.clone();
-								project.description = current_project.description.clone();
-							}
+							let result = self.storage.lock().unwrap().update_project(projects_data, &current_project.id, &current_project.name, &current_project.description);
+							log_storage_error("updating project", result);
 						},
 						_ => {},
 					}
@@ -230,6 +472,49 @@ impl AppState {
 		}
 	}
 
+	fn delete_selected_projects(&mut self) {
+		let selected_project_ids = self.get_selected_project_ids();
+
+		if let Some(projects_data) = &mut self.projects_data {
+			let result = self.storage.lock().unwrap().delete_projects(projects_data, &selected_project_ids);
+			log_storage_error("deleting projects", result);
+		}
+
+		if let Some(project_list_state) = &mut self.project_list_state {
+			project_list_state.selected_projects.clear();
+		}
+	}
+
+	fn archive_selected_projects(&mut self) {
+		let selected_project_ids = self.get_selected_project_ids();
+
+		if let Some(projects_data) = &mut self.projects_data {
+			let result = self.storage.lock().unwrap().archive_projects(projects_data, &selected_project_ids);
+			log_storage_error("archiving projects", result);
+		}
+
+		if let Some(project_list_state) = &mut self.project_list_state {
+			project_list_state.selected_projects.clear();
+		}
+	}
+
+	fn mutate_task(&mut self, project_id: Uuid, task_id: Uuid, mutate: impl FnOnce(&mut ProjectTask)) {
+		let updated_task = self.projects_data.as_mut()
+			.and_then(|projects_data| projects_data.get_project_mut(&project_id))
+			.and_then(|project| project.get_task_mut(&task_id))
+			.map(|task| {
+				mutate(task);
+				task.clone()
+			});
+
+		if let Some(task) = updated_task {
+			if let Some(projects_data) = &mut self.projects_data {
+				let result = self.storage.lock().unwrap().update_task(projects_data, &task);
+				log_storage_error("updating task", result);
+			}
+		}
+	}
+
 	fn is_project_selected(&self, project_id: &Uuid) -> bool {
 		match &self.project_list_state {
 			None => false,
@@ -250,7 +535,37 @@ impl AppState {
 	}
 
 	fn project_list(&self) -> Element<Message> {
-		let projects = &self.get_projects();
+		let search_query = match &self.project_list_state {
+			None => "",
+			Some(project_list_state) => project_list_state.search_query.as_str(),
+		};
+
+		let show_archived = match &self.project_list_state {
+			None => false,
+			Some(project_list_state) => project_list_state.show_archived,
+		};
+
+		let search_box = text_input("Search projects", search_query)
+			.on_input(Message::ProjectSearchChange);
+
+		let show_archived_toggle = checkbox("Show archived", show_archived)
+			.on_toggle(Message::ToggleShowArchived);
+
+		let selected_project_ids = self.get_selected_project_ids();
+
+		let bulk_actions: Element<Message> = if selected_project_ids.is_empty() {
+			column![].into()
+		} else {
+			row![
+				text(format!("{} selected", selected_project_ids.len())),
+				button("Archive").on_press(Message::ArchiveSelectedProjects),
+				button("Delete").on_press(Message::DeleteSelectedProjects),
+			]
+			.spacing(15)
+			.into()
+		};
+
+		let projects = &self.filtered_projects();
 		let project_list: Vec<Element<Message>> = projects.iter().map(|project| {
 			let is_project_selected = self.is_project_selected(&project.id);
 
@@ -265,18 +580,21 @@ impl AppState {
 				.push(checkbox("", is_project_selected).on_toggle(select_project))
 				.push(text(project.name.clone()))
 				.push(text(project.description.clone()))
+				.push(button("Open").on_press(Message::OpenProject(project.id)))
 				.push(button("Edit").on_press(Message::EditProject(project.id)))
 				.spacing(15)
 				.into()
 		}).collect();
 
-		if projects.is_empty() {
+		if self.get_projects().is_empty() {
 			return text("You have no projects").into();
 		}
 
 		let all_projects_selected = self.get_selected_project_ids().len() == projects.len();
 
-		container(
+		let project_list_content: Element<Message> = if projects.is_empty() {
+			text("No projects match your search").into()
+		} else {
 			column![
 				checkbox("Select All", all_projects_selected)
 					.on_toggle(Message::ProjectListSelectAllProjects),
@@ -284,6 +602,16 @@ impl AppState {
 					.spacing(10)
 			]
 				.spacing(10)
+				.into()
+		};
+
+		container(
+			column![
+				row![search_box, show_archived_toggle].spacing(15),
+				bulk_actions,
+				project_list_content,
+			]
+				.spacing(10)
 		)
 			.width(Length::Fill)
 			.into()
@@ -293,7 +621,11 @@ impl AppState {
 		column![
 			heading("Projects"),
 			container(
-				button("New Project").on_press(Message::NewProject),
+				row![
+					button("Focus").on_press(Message::EnterFocusMode),
+					button("New Project").on_press(Message::NewProject),
+				]
+				.spacing(5)
 			)
 			.width(Length::Fill)
 			.align_x(Right),
@@ -339,6 +671,135 @@ impl AppState {
 		}
 	}
 
+	fn view_project_detail(&self, project_id: &Uuid) -> Element<Message> {
+		let projects_data = match &self.projects_data {
+			None => return text("No project found").into(),
+			Some(projects_data) => projects_data,
+		};
+
+		let project = match projects_data.get_project(project_id) {
+			None => return text("No project found").into(),
+			Some(project) => project,
+		};
+
+		let project_detail_state = match &self.project_detail_state {
+			None => return text("No project detail state found").into(),
+			Some(project_detail_state) => project_detail_state,
+		};
+
+		let task_list: Vec<Element<Message>> = project.tasks.iter().map(|project_task| {
+			task_row(project.id, project_task)
+		}).collect();
+
+		container(
+			column![
+				heading(project.name.as_str()),
+				column(task_list).spacing(10),
+				row![
+					text_input("New task title", project_detail_state.new_task_title.as_str())
+						.on_input(Message::NewTaskTitleChange),
+					text_input("New task description", project_detail_state.new_task_description.as_str())
+						.on_input(Message::NewTaskDescriptionChange),
+					button("Add Task").on_press(Message::NewTask(project.id)),
+				]
+				.spacing(10),
+				container(
+					row![
+						button("Board").on_press(Message::OpenBoard(project.id)),
+						button("Back").on_press(Message::CloseProjectDetail),
+					]
+					.spacing(5)
+				)
+				.width(Length::Fill)
+				.align_x(Right),
+			]
+			.spacing(20)
+		)
+		.padding(20)
+		.into()
+	}
+
+	fn view_board(&self, project_id: &Uuid) -> Element<Message> {
+		let projects_data = match &self.projects_data {
+			None => return text("No project found").into(),
+			Some(projects_data) => projects_data,
+		};
+
+		let project = match projects_data.get_project(project_id) {
+			None => return text("No project found").into(),
+			Some(project) => project,
+		};
+
+		let todo_tasks: Vec<&ProjectTask> = project.tasks.iter().filter(|task| task.status == TaskStatus::Todo).collect();
+		let in_progress_tasks: Vec<&ProjectTask> = project.tasks.iter().filter(|task| task.status == TaskStatus::InProgress).collect();
+		let done_tasks: Vec<&ProjectTask> = project.tasks.iter().filter(|task| task.status == TaskStatus::Done).collect();
+
+		let dragged_task_id = self.drag_state.as_ref().map(|drag_state| drag_state.task_id);
+		let hovered_status = self.drag_state.as_ref().and_then(|drag_state| drag_state.hovered_status);
+
+		container(
+			column![
+				heading(project.name.as_str()),
+				Row::new()
+					.push(board_column("Todo", TaskStatus::Todo, &todo_tasks, dragged_task_id, hovered_status == Some(TaskStatus::Todo)))
+					.push(board_column("In Progress", TaskStatus::InProgress, &in_progress_tasks, dragged_task_id, hovered_status == Some(TaskStatus::InProgress)))
+					.push(board_column("Done", TaskStatus::Done, &done_tasks, dragged_task_id, hovered_status == Some(TaskStatus::Done)))
+					.spacing(20),
+				container(
+					button("Back").on_press(Message::CloseBoard(project.id)),
+				)
+				.width(Length::Fill)
+				.align_x(Right),
+			]
+			.spacing(20)
+		)
+		.padding(20)
+		.into()
+	}
+
+	fn view_focus(&self) -> Element<Message> {
+		let projects_data = match &self.projects_data {
+			None => return text("No project found").into(),
+			Some(projects_data) => projects_data,
+		};
+
+		let excluded = match &self.focus_state {
+			None => Vec::new(),
+			Some(focus_state) => focus_state.skipped.clone(),
+		};
+
+		let focus_task = projects_data.next_focus_task(&excluded).map(|(_, task)| task);
+
+		let content: Element<Message> = match &focus_task {
+			None => text("Nothing left to do").into(),
+			Some(focus_task) => column![
+				text(focus_task.title.clone()).size(30),
+				text(focus_task.description.clone()),
+				row![
+					button("Done").on_press(Message::FocusDone),
+					button("Skip").on_press(Message::FocusSkip),
+				]
+				.spacing(10),
+			]
+			.spacing(20)
+			.into(),
+		};
+
+		container(
+			column![
+				content,
+				container(
+					button("Exit Focus Mode").on_press(Message::ExitFocusMode),
+				)
+				.width(Length::Fill)
+				.align_x(Right),
+			]
+			.spacing(20)
+		)
+		.padding(20)
+		.into()
+	}
+
 }
 
 impl App {
@@ -465,7 +926,7 @@ impl App {
 					},
 					Message::ProjectListSelectAllProjects(selected) => {
 						let project_ids: Vec<Uuid> = if selected {
-							state.get_projects().iter().map(|project| project.id).collect()
+							state.filtered_projects().iter().map(|project| project.id).collect()
 						} else {
 							Vec::new()
 						};
@@ -476,6 +937,179 @@ impl App {
 
 						Task::none()
 					},
+					Message::OpenProject(project_id) => {
+						state.context = Some(Context::ProjectDetail(project_id));
+						state.project_detail_state = Some(ProjectDetailState::new());
+
+						Task::none()
+					},
+					Message::CloseProjectDetail => {
+						state.context = Some(Context::ProjectList);
+						state.project_detail_state = None;
+
+						Task::none()
+					},
+					Message::NewTaskTitleChange(title) => {
+						if let Some(project_detail_state) = &mut state.project_detail_state {
+							project_detail_state.new_task_title = title;
+						}
+
+						Task::none()
+					},
+					Message::NewTaskDescriptionChange(description) => {
+						if let Some(project_detail_state) = &mut state.project_detail_state {
+							project_detail_state.new_task_description = description;
+						}
+
+						Task::none()
+					},
+					Message::NewTask(project_id) => {
+						let new_task_title = state.project_detail_state.as_ref().map(|project_detail_state| project_detail_state.new_task_title.clone()).unwrap_or_default();
+						let new_task_description = state.project_detail_state.as_ref().map(|project_detail_state| project_detail_state.new_task_description.clone()).unwrap_or_default();
+
+						if !new_task_title.is_empty() {
+							if let Some(projects_data) = &mut state.projects_data {
+								let result = state.storage.lock().unwrap().create_task(projects_data, &project_id, &new_task_title, &new_task_description);
+								log_storage_error("creating task", result);
+							}
+						}
+
+						state.project_detail_state = Some(ProjectDetailState::new());
+
+						Task::none()
+					},
+					Message::TaskTitleChange(project_id, task_id, title) => {
+						state.mutate_task(project_id, task_id, |task| task.title = title);
+
+						Task::none()
+					},
+					Message::TaskDescriptionChange(project_id, task_id, description) => {
+						state.mutate_task(project_id, task_id, |task| task.description = description);
+
+						Task::none()
+					},
+					Message::TaskStatusChange(project_id, task_id, status) => {
+						state.mutate_task(project_id, task_id, |task| task.status = status);
+
+						Task::none()
+					},
+					Message::TaskPriorityChange(project_id, task_id, delta) => {
+						state.mutate_task(project_id, task_id, |task| task.priority += delta);
+
+						Task::none()
+					},
+					Message::OpenBoard(project_id) => {
+						state.context = Some(Context::Board(project_id));
+
+						Task::none()
+					},
+					Message::CloseBoard(project_id) => {
+						state.context = Some(Context::ProjectDetail(project_id));
+						state.drag_state = None;
+
+						Task::none()
+					},
+					Message::TaskDragStarted(task_id) => {
+						if let Some(Context::Board(project_id)) = &state.context {
+							state.drag_state = Some(DragState {
+								project_id: *project_id,
+								task_id,
+								cursor: Point::ORIGIN,
+								hovered_status: None,
+							});
+						}
+
+						Task::none()
+					},
+					Message::TaskDragMoved(cursor) => {
+						if let Some(drag_state) = &mut state.drag_state {
+							drag_state.cursor = cursor;
+						}
+
+						Task::none()
+					},
+					Message::TaskDragHover(status) => {
+						if let Some(drag_state) = &mut state.drag_state {
+							drag_state.hovered_status = Some(status);
+						}
+
+						Task::none()
+					},
+					Message::TaskDropped { status } => {
+						if let Some(drag_state) = state.drag_state.take() {
+							state.mutate_task(drag_state.project_id, drag_state.task_id, |task| task.status = status);
+						}
+
+						Task::none()
+					},
+					Message::ProjectSearchChange(search_query) => {
+						if let Some(project_list_state) = &mut state.project_list_state {
+							project_list_state.search_query = search_query;
+						}
+
+						Task::none()
+					},
+					Message::ToggleShowArchived(show_archived) => {
+						if let Some(project_list_state) = &mut state.project_list_state {
+							project_list_state.show_archived = show_archived;
+						}
+
+						Task::none()
+					},
+					Message::DeleteSelectedProjects => {
+						state.delete_selected_projects();
+
+						Task::none()
+					},
+					Message::ArchiveSelectedProjects => {
+						state.archive_selected_projects();
+
+						Task::none()
+					},
+					Message::EnterFocusMode => {
+						state.context = Some(Context::Focus);
+						state.focus_state = Some(FocusState::new());
+
+						Task::none()
+					},
+					Message::ExitFocusMode => {
+						state.context = Some(Context::ProjectList);
+						state.focus_state = None;
+
+						Task::none()
+					},
+					Message::FocusDone => {
+						let excluded = match &state.focus_state {
+							None => Vec::new(),
+							Some(focus_state) => focus_state.skipped.clone(),
+						};
+
+						let candidate = state.projects_data.as_ref()
+							.and_then(|projects_data| projects_data.next_focus_task(&excluded));
+
+						if let Some((project_id, focus_task)) = candidate {
+							state.mutate_task(project_id, focus_task.id, |task| task.status = TaskStatus::Done);
+						}
+
+						Task::none()
+					},
+					Message::FocusSkip => {
+						let excluded = match &state.focus_state {
+							None => Vec::new(),
+							Some(focus_state) => focus_state.skipped.clone(),
+						};
+
+						let candidate = state.projects_data.as_ref()
+							.and_then(|projects_data| projects_data.next_focus_task(&excluded));
+
+						if let Some((_, focus_task)) = candidate {
+							if let Some(focus_state) = &mut state.focus_state {
+								focus_state.skipped.push(focus_task.id);
+							}
+						}
+
+						Task::none()
+					},
 					_ => Task::none(),
 				}
 			}
@@ -498,7 +1132,20 @@ impl App {
 			Message::AppSync
 		});
 
-		Subscription::batch(vec![tick])
+		let mut subscriptions = vec![tick];
+
+		if let App::Loaded(state) = self {
+			if state.drag_state.is_some() {
+				subscriptions.push(iced::event::listen_with(|event, _status, _id| {
+					match event {
+						Event::Mouse(mouse::Event::CursorMoved { position }) => Some(Message::TaskDragMoved(position)),
+						_ => None,
+					}
+				}));
+			}
+		}
+
+		Subscription::batch(subscriptions)
 	}
 }
 
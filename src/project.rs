@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub mod storage;
+
+pub use storage::{FileStorage, SqliteStorage, Storage};
+
+const DATA_FILE_NAME: &str = "taskmanager.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TaskStatus {
+	Todo,
+	InProgress,
+	Done,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+	pub id: Uuid,
+	pub title: String,
+	pub description: String,
+	pub status: TaskStatus,
+	pub created_at: i64,
+	#[serde(default)]
+	pub priority: i32,
+}
+
+impl Task {
+	pub fn new(title: &str, description: &str) -> Self {
+		Self {
+			id: Uuid::new_v4(),
+			title: title.to_string(),
+			description: description.to_string(),
+			status: TaskStatus::Todo,
+			created_at: now_timestamp(),
+			priority: 0,
+		}
+	}
+}
+
+fn now_timestamp() -> i64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|duration| duration.as_secs() as i64)
+		.unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+	pub id: Uuid,
+	pub name: String,
+	pub description: String,
+	pub tasks: Vec<Task>,
+	#[serde(default)]
+	pub archived: bool,
+}
+
+impl Project {
+	pub fn new(name: &str, description: &str) -> Self {
+		Self {
+			id: Uuid::new_v4(),
+			name: name.to_string(),
+			description: description.to_string(),
+			tasks: Vec::new(),
+			archived: false,
+		}
+	}
+
+	pub fn add_task(&mut self, title: &str, description: &str) -> &Task {
+		self.tasks.push(Task::new(title, description));
+		self.tasks.last().unwrap()
+	}
+
+	pub fn get_task_mut(&mut self, task_id: &Uuid) -> Option<&mut Task> {
+		self.tasks.iter_mut().find(|task| &task.id == task_id)
+	}
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectData {
+	projects: Vec<Project>,
+}
+
+impl ProjectData {
+	pub fn get_projects(&self) -> Vec<Project> {
+		self.projects.clone()
+	}
+
+	pub fn get_project(&self, id: &Uuid) -> Option<&Project> {
+		self.projects.iter().find(|project| &project.id == id)
+	}
+
+	pub fn get_project_mut(&mut self, id: &Uuid) -> Option<&mut Project> {
+		self.projects.iter_mut().find(|project| &project.id == id)
+	}
+
+	pub fn create_project(&mut self, name: &str, description: &str) -> &Project {
+		self.projects.push(Project::new(name, description));
+		self.projects.last().unwrap()
+	}
+
+	pub fn delete_projects(&mut self, ids: &[Uuid]) {
+		self.projects.retain(|project| !ids.contains(&project.id));
+	}
+
+	pub fn next_focus_task(&self, excluded: &[Uuid]) -> Option<(Uuid, Task)> {
+		self.projects.iter()
+			.flat_map(|project| project.tasks.iter().map(move |task| (project.id, task)))
+			.filter(|(_, task)| task.status != TaskStatus::Done && !excluded.contains(&task.id))
+			.min_by(|(_, a), (_, b)| {
+				a.status.cmp(&b.status)
+					.then_with(|| b.priority.cmp(&a.priority))
+					.then_with(|| a.created_at.cmp(&b.created_at))
+			})
+			.map(|(project_id, task)| (project_id, task.clone()))
+	}
+}
+
+fn data_file_path() -> PathBuf {
+	PathBuf::from(DATA_FILE_NAME)
+}
+
+pub fn load_data() -> Result<ProjectData, String> {
+	let path = data_file_path();
+
+	if !path.exists() {
+		return Ok(ProjectData::default());
+	}
+
+	let content = fs::read_to_string(&path).map_err(|error| error.to_string())?;
+
+	serde_json::from_str(&content).map_err(|error| error.to_string())
+}
+
+pub fn write_data(data: &ProjectData) -> Result<(), String> {
+	let content = serde_json::to_string_pretty(data).map_err(|error| error.to_string())?;
+
+	fs::write(data_file_path(), content).map_err(|error| error.to_string())
+}
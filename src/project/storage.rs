@@ -0,0 +1,335 @@
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+use super::{load_data, write_data, Project, ProjectData, Task, TaskStatus};
+
+pub trait Storage: std::fmt::Debug {
+	fn load(&mut self) -> Result<ProjectData, String>;
+	fn save(&mut self, data: &ProjectData) -> Result<(), String>;
+	fn create_project(&mut self, data: &mut ProjectData, name: &str, description: &str) -> Result<(), String>;
+	fn update_project(&mut self, data: &mut ProjectData, project_id: &Uuid, name: &str, description: &str) -> Result<(), String>;
+	fn delete_projects(&mut self, data: &mut ProjectData, ids: &[Uuid]) -> Result<(), String>;
+	fn archive_projects(&mut self, data: &mut ProjectData, ids: &[Uuid]) -> Result<(), String>;
+	fn create_task(&mut self, data: &mut ProjectData, project_id: &Uuid, title: &str, description: &str) -> Result<(), String>;
+	fn update_task(&mut self, data: &mut ProjectData, task: &Task) -> Result<(), String>;
+}
+
+#[derive(Debug)]
+pub struct FileStorage;
+
+impl FileStorage {
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+impl Default for FileStorage {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Storage for FileStorage {
+	fn load(&mut self) -> Result<ProjectData, String> {
+		load_data()
+	}
+
+	fn save(&mut self, data: &ProjectData) -> Result<(), String> {
+		write_data(data)
+	}
+
+	fn create_project(&mut self, data: &mut ProjectData, name: &str, description: &str) -> Result<(), String> {
+		data.create_project(name, description);
+		self.save(data)
+	}
+
+	fn update_project(&mut self, data: &mut ProjectData, project_id: &Uuid, name: &str, description: &str) -> Result<(), String> {
+		if let Some(project) = data.get_project_mut(project_id) {
+			project.name = name.to_string();
+			project.description = description.to_string();
+		}
+
+		self.save(data)
+	}
+
+	fn delete_projects(&mut self, data: &mut ProjectData, ids: &[Uuid]) -> Result<(), String> {
+		data.delete_projects(ids);
+		self.save(data)
+	}
+
+	fn archive_projects(&mut self, data: &mut ProjectData, ids: &[Uuid]) -> Result<(), String> {
+		for project in data.projects.iter_mut() {
+			if ids.contains(&project.id) {
+				project.archived = true;
+			}
+		}
+
+		self.save(data)
+	}
+
+	fn create_task(&mut self, data: &mut ProjectData, project_id: &Uuid, title: &str, description: &str) -> Result<(), String> {
+		if let Some(project) = data.get_project_mut(project_id) {
+			project.add_task(title, description);
+		}
+
+		self.save(data)
+	}
+
+	// Task title/description edits fire on every keystroke, so unlike the other
+	// mutations above this one does not flush to disk immediately - the change
+	// is instead picked up by the periodic AppSync save (or an explicit save()).
+	fn update_task(&mut self, data: &mut ProjectData, task: &Task) -> Result<(), String> {
+		for project in data.projects.iter_mut() {
+			if let Some(existing_task) = project.get_task_mut(&task.id) {
+				*existing_task = task.clone();
+			}
+		}
+
+		Ok(())
+	}
+}
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS projects (
+	id TEXT PRIMARY KEY,
+	name TEXT NOT NULL,
+	description TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS tasks (
+	id TEXT PRIMARY KEY,
+	project_id TEXT NOT NULL REFERENCES projects(id),
+	title TEXT NOT NULL,
+	description TEXT NOT NULL,
+	status TEXT NOT NULL,
+	created_at INTEGER NOT NULL
+);
+";
+
+const ARCHIVED_COLUMN_MIGRATION: &str = "ALTER TABLE projects ADD COLUMN archived INTEGER NOT NULL DEFAULT 0";
+const PRIORITY_COLUMN_MIGRATION: &str = "ALTER TABLE tasks ADD COLUMN priority INTEGER NOT NULL DEFAULT 0";
+
+pub struct SqliteStorage {
+	conn: Connection,
+}
+
+impl std::fmt::Debug for SqliteStorage {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SqliteStorage").finish()
+	}
+}
+
+fn status_to_str(status: TaskStatus) -> &'static str {
+	match status {
+		TaskStatus::Todo => "todo",
+		TaskStatus::InProgress => "in_progress",
+		TaskStatus::Done => "done",
+	}
+}
+
+fn status_from_str(value: &str) -> TaskStatus {
+	match value {
+		"in_progress" => TaskStatus::InProgress,
+		"done" => TaskStatus::Done,
+		_ => TaskStatus::Todo,
+	}
+}
+
+impl SqliteStorage {
+	pub fn new(path: &Path) -> Result<Self, String> {
+		let conn = Connection::open(path).map_err(|error| error.to_string())?;
+
+		conn.execute_batch(SCHEMA).map_err(|error| error.to_string())?;
+
+		if let Err(error) = conn.execute(ARCHIVED_COLUMN_MIGRATION, []) {
+			if !error.to_string().contains("duplicate column name") {
+				return Err(error.to_string());
+			}
+		}
+
+		if let Err(error) = conn.execute(PRIORITY_COLUMN_MIGRATION, []) {
+			if !error.to_string().contains("duplicate column name") {
+				return Err(error.to_string());
+			}
+		}
+
+		Ok(Self { conn })
+	}
+}
+
+impl Storage for SqliteStorage {
+	fn load(&mut self) -> Result<ProjectData, String> {
+		let mut projects_stmt = self.conn.prepare("SELECT id, name, description, archived FROM projects")
+			.map_err(|error| error.to_string())?;
+		let mut tasks_stmt = self.conn.prepare("SELECT id, title, description, status, created_at, priority FROM tasks WHERE project_id = ?1")
+			.map_err(|error| error.to_string())?;
+
+		let project_rows = projects_stmt.query_map([], |row| {
+			let id: String = row.get(0)?;
+			let name: String = row.get(1)?;
+			let description: String = row.get(2)?;
+			let archived: i64 = row.get(3)?;
+			Ok((id, name, description, archived))
+		}).map_err(|error| error.to_string())?;
+
+		let mut projects = Vec::new();
+
+		for project_row in project_rows {
+			let (id, name, description, archived) = project_row.map_err(|error| error.to_string())?;
+			let project_id = Uuid::parse_str(&id).map_err(|error| error.to_string())?;
+
+			let task_rows = tasks_stmt.query_map(params![id], |row| {
+				let task_id: String = row.get(0)?;
+				let title: String = row.get(1)?;
+				let task_description: String = row.get(2)?;
+				let status: String = row.get(3)?;
+				let created_at: i64 = row.get(4)?;
+				let priority: i32 = row.get(5)?;
+				Ok((task_id, title, task_description, status, created_at, priority))
+			}).map_err(|error| error.to_string())?;
+
+			let mut tasks = Vec::new();
+
+			for task_row in task_rows {
+				let (task_id, title, task_description, status, created_at, priority) = task_row.map_err(|error| error.to_string())?;
+
+				tasks.push(Task {
+					id: Uuid::parse_str(&task_id).map_err(|error| error.to_string())?,
+					title,
+					description: task_description,
+					status: status_from_str(&status),
+					created_at,
+					priority,
+				});
+			}
+
+			projects.push(Project {
+				id: project_id,
+				name,
+				description,
+				tasks,
+				archived: archived != 0,
+			});
+		}
+
+		Ok(ProjectData { projects })
+	}
+
+	// Every mutation below lands its own statement (or small transaction) the
+	// moment it happens, so there is nothing left to re-sync here; a periodic
+	// full-table rewrite would only add crash-vulnerable work for no benefit.
+	fn save(&mut self, _data: &ProjectData) -> Result<(), String> {
+		Ok(())
+	}
+
+	fn create_project(&mut self, data: &mut ProjectData, name: &str, description: &str) -> Result<(), String> {
+		let project = data.create_project(name, description).clone();
+
+		self.conn.execute(
+			"INSERT INTO projects (id, name, description, archived) VALUES (?1, ?2, ?3, ?4)",
+			params![project.id.to_string(), project.name, project.description, project.archived],
+		).map_err(|error| error.to_string())?;
+
+		Ok(())
+	}
+
+	fn update_project(&mut self, data: &mut ProjectData, project_id: &Uuid, name: &str, description: &str) -> Result<(), String> {
+		if let Some(project) = data.get_project_mut(project_id) {
+			project.name = name.to_string();
+			project.description = description.to_string();
+		}
+
+		self.conn.execute(
+			"UPDATE projects SET name = ?1, description = ?2 WHERE id = ?3",
+			params![name, description, project_id.to_string()],
+		).map_err(|error| error.to_string())?;
+
+		Ok(())
+	}
+
+	fn delete_projects(&mut self, data: &mut ProjectData, ids: &[Uuid]) -> Result<(), String> {
+		data.delete_projects(ids);
+
+		if ids.is_empty() {
+			return Ok(());
+		}
+
+		let tx = self.conn.transaction().map_err(|error| error.to_string())?;
+		let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+		let id_strings: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+		let sql_params: Vec<&dyn rusqlite::ToSql> = id_strings.iter().map(|id| id as &dyn rusqlite::ToSql).collect();
+
+		tx.execute(
+			&format!("DELETE FROM tasks WHERE project_id IN ({})", placeholders),
+			sql_params.as_slice(),
+		).map_err(|error| error.to_string())?;
+
+		tx.execute(
+			&format!("DELETE FROM projects WHERE id IN ({})", placeholders),
+			sql_params.as_slice(),
+		).map_err(|error| error.to_string())?;
+
+		tx.commit().map_err(|error| error.to_string())
+	}
+
+	fn archive_projects(&mut self, data: &mut ProjectData, ids: &[Uuid]) -> Result<(), String> {
+		for project in data.projects.iter_mut() {
+			if ids.contains(&project.id) {
+				project.archived = true;
+			}
+		}
+
+		if ids.is_empty() {
+			return Ok(());
+		}
+
+		let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+		let id_strings: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+		let sql_params: Vec<&dyn rusqlite::ToSql> = id_strings.iter().map(|id| id as &dyn rusqlite::ToSql).collect();
+
+		self.conn.execute(
+			&format!("UPDATE projects SET archived = 1 WHERE id IN ({})", placeholders),
+			sql_params.as_slice(),
+		).map_err(|error| error.to_string())?;
+
+		Ok(())
+	}
+
+	fn create_task(&mut self, data: &mut ProjectData, project_id: &Uuid, title: &str, description: &str) -> Result<(), String> {
+		let task = match data.get_project_mut(project_id) {
+			None => return Ok(()),
+			Some(project) => project.add_task(title, description).clone(),
+		};
+
+		self.conn.execute(
+			"INSERT INTO tasks (id, project_id, title, description, status, created_at, priority) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+			params![
+				task.id.to_string(),
+				project_id.to_string(),
+				task.title,
+				task.description,
+				status_to_str(task.status),
+				task.created_at,
+				task.priority,
+			],
+		).map_err(|error| error.to_string())?;
+
+		Ok(())
+	}
+
+	fn update_task(&mut self, data: &mut ProjectData, task: &Task) -> Result<(), String> {
+		for project in data.projects.iter_mut() {
+			if let Some(existing_task) = project.get_task_mut(&task.id) {
+				*existing_task = task.clone();
+			}
+		}
+
+		self.conn.execute(
+			"UPDATE tasks SET title = ?1, description = ?2, status = ?3, priority = ?4 WHERE id = ?5",
+			params![task.title, task.description, status_to_str(task.status), task.priority, task.id.to_string()],
+		).map_err(|error| error.to_string())?;
+
+		Ok(())
+	}
+}